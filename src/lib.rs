@@ -4,6 +4,9 @@
 #[macro_use]
 extern crate hex_literal;
 
+use zerocopy::byteorder::little_endian::U16;
+use zerocopy::{FromBytes, Immutable, KnownLayout, Unaligned};
+
 #[derive(Default, PartialEq, Debug)]
 pub struct Data {
     pub channels: [u16; 16],
@@ -11,19 +14,55 @@ pub struct Data {
     pub channel18: bool,
     pub frame_lost: bool,
     pub failsafe: bool,
+    pub footer_kind: FooterKind,
+}
+
+/// What, if anything, trails this frame on the wire, decoded from the footer byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FooterKind {
+    #[default]
+    Sbus1,
+    Sbus2ReceiverVoltage,
+    Sbus2GpsOrBaro,
+    Sbus2Unknown(u8),
+}
+
+impl FooterKind {
+    fn from_footer(footer: u8) -> Self {
+        match footer {
+            0x4 => FooterKind::Sbus2ReceiverVoltage,
+            0x14 => FooterKind::Sbus2GpsOrBaro,
+            0x24 | 0x34 => FooterKind::Sbus2Unknown(footer),
+            _ => FooterKind::Sbus1,
+        }
+    }
+
+    fn is_sbus2(self) -> bool {
+        !matches!(self, FooterKind::Sbus1)
+    }
+
+    /// Inverse of `from_footer`: the footer byte that decodes back to this kind.
+    fn to_footer(self) -> u8 {
+        match self {
+            FooterKind::Sbus1 => 0x0,
+            FooterKind::Sbus2ReceiverVoltage => 0x4,
+            FooterKind::Sbus2GpsOrBaro => 0x14,
+            FooterKind::Sbus2Unknown(footer) => footer,
+        }
+    }
 }
 
 #[repr(C)]
+#[derive(FromBytes, Immutable, KnownLayout, Unaligned)]
 pub struct Packet {
-    _padding: u8,
     header: u8,
-    channel_words: [u16; 11],
+    channel_words: [U16; 11],
     digital_and_flags: u8,
     footer: u8,
 }
 
 pub const SBUS_PACKET_BEGIN: u8 = 0xF;
-pub const SBUS_PACKET_SIZE: usize = core::mem::size_of::<Packet>() - 1;
+pub const SBUS_PACKET_SIZE: usize = core::mem::size_of::<Packet>();
 
 pub fn is_sbus_packet_end(byte: u8) -> bool {
     match byte {
@@ -36,6 +75,85 @@ pub fn is_sbus_packet_end(byte: u8) -> bool {
     }
 }
 
+impl Data {
+    /// Serialize back into a 25-byte S.BUS frame, the inverse of `Packet::parse`.
+    /// The footer byte is derived from `footer_kind`, so a decoded S.BUS2 frame
+    /// round-trips with its telemetry signal intact.
+    pub fn encode(&self) -> [u8; SBUS_PACKET_SIZE] {
+        let mut frame = [0u8; SBUS_PACKET_SIZE];
+        frame[0] = SBUS_PACKET_BEGIN;
+
+        let mut bits: u32 = 0;
+        let mut bit_count = 0u32;
+        let mut byte_index = 1;
+        for &channel in self.channels.iter() {
+            bits |= (channel as u32 & 0x7FF) << bit_count;
+            bit_count += 11;
+            while bit_count >= 8 {
+                frame[byte_index] = bits as u8;
+                byte_index += 1;
+                bits >>= 8;
+                bit_count -= 8;
+            }
+        }
+
+        let mut flags = 0u8;
+        flags |= (self.channel17 as u8) << 7;
+        flags |= (self.channel18 as u8) << 6;
+        flags |= (self.frame_lost as u8) << 5;
+        flags |= (self.failsafe as u8) << 4;
+        frame[SBUS_PACKET_SIZE - 2] = flags;
+        frame[SBUS_PACKET_SIZE - 1] = self.footer_kind.to_footer();
+
+        frame
+    }
+
+    const CHANNEL_RAW_MIN: u16 = 172;
+    const CHANNEL_RAW_MAX: u16 = 1811;
+    #[cfg(feature = "float-channels")]
+    const CHANNEL_RAW_CENTER: u16 = 992;
+    const CHANNEL_US_MIN: u16 = 1000;
+    const CHANNEL_US_MAX: u16 = 2000;
+
+    /// Raw 11-bit channel value mapped to a servo pulse width in microseconds
+    /// (172 -> 1000us, 1811 -> 2000us, linear and rounded, clamped to that range).
+    pub fn channel_us(&self, index: usize) -> u16 {
+        let raw = self.channels[index].clamp(Self::CHANNEL_RAW_MIN, Self::CHANNEL_RAW_MAX);
+        let raw_range = (Self::CHANNEL_RAW_MAX - Self::CHANNEL_RAW_MIN) as u32;
+        let us_range = (Self::CHANNEL_US_MAX - Self::CHANNEL_US_MIN) as u32;
+        let offset = (raw - Self::CHANNEL_RAW_MIN) as u32;
+        Self::CHANNEL_US_MIN + ((offset * us_range + raw_range / 2) / raw_range) as u16
+    }
+
+    /// Inverse of `channel_us`: a servo pulse width in microseconds to a raw
+    /// 11-bit channel value, clamped to the standard S.BUS range.
+    pub fn from_us(us: u16) -> u16 {
+        let us = us.clamp(Self::CHANNEL_US_MIN, Self::CHANNEL_US_MAX);
+        let raw_range = (Self::CHANNEL_RAW_MAX - Self::CHANNEL_RAW_MIN) as u32;
+        let us_range = (Self::CHANNEL_US_MAX - Self::CHANNEL_US_MIN) as u32;
+        let offset = (us - Self::CHANNEL_US_MIN) as u32;
+        Self::CHANNEL_RAW_MIN + ((offset * raw_range + us_range / 2) / us_range) as u16
+    }
+
+    /// Raw 11-bit channel value mapped to a -1.0..1.0 stick position, with 992
+    /// as center.
+    #[cfg(feature = "float-channels")]
+    pub fn channel_norm(&self, index: usize) -> f32 {
+        let raw = self.channels[index].clamp(Self::CHANNEL_RAW_MIN, Self::CHANNEL_RAW_MAX);
+        let half_range = (Self::CHANNEL_RAW_MAX - Self::CHANNEL_RAW_CENTER) as f32;
+        ((raw as f32 - Self::CHANNEL_RAW_CENTER as f32) / half_range).clamp(-1.0, 1.0)
+    }
+
+    /// Inverse of `channel_norm`: a -1.0..1.0 stick position to a raw 11-bit
+    /// channel value, clamped to the standard S.BUS range.
+    #[cfg(feature = "float-channels")]
+    pub fn from_norm(norm: f32) -> u16 {
+        let norm = norm.clamp(-1.0, 1.0);
+        let half_range = (Self::CHANNEL_RAW_MAX - Self::CHANNEL_RAW_CENTER) as f32;
+        (Self::CHANNEL_RAW_CENTER as f32 + norm * half_range) as u16
+    }
+}
+
 impl Packet {
     pub fn parse(&self) -> Data {
         const SHIFT: [u8; 16] = [0, 5, 10, 15, 4, 9, 14, 3, 8, 13, 2, 7, 12, 1, 6, 11];
@@ -44,7 +162,7 @@ impl Packet {
         let mut data = Data::default();
         let mut bits: u32 = 0;
         for i in 0..16 {
-            let word = u16::from_le(self.channel_words[INDEX[i] as usize]) as u32;
+            let word = self.channel_words[INDEX[i] as usize].get() as u32;
             bits |= word << (SHIFT[i] as usize);
             data.channels[i] = bits as u16 & ((1 << 11) - 1);
             bits >>= 11;
@@ -54,63 +172,222 @@ impl Packet {
         data.channel18 = (self.digital_and_flags & (1 << 6)) > 0;
         data.frame_lost = (self.digital_and_flags & (1 << 5)) > 0;
         data.failsafe = (self.digital_and_flags & (1 << 4)) > 0;
+        data.footer_kind = FooterKind::from_footer(self.footer);
         data
     }
 }
 
+/// Why a candidate frame was rejected, as opposed to simply not yet complete.
+///
+/// There is intentionally no "discarded partial buffer, nothing to resync on"
+/// variant: `find_partial_packet` only ever sets `size > 0` after placing
+/// `SBUS_PACKET_BEGIN` at `packet[0]`, so `continue_receive`'s scan over the
+/// buffered bytes always either completes the frame, buffers more, or records
+/// a `BadFooter` at that first byte before running out of candidates — a
+/// third "gave up with no reason" case can't be reached with this algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReceiveError {
+    /// A 25-byte window led by `SBUS_PACKET_BEGIN` ended in a byte that
+    /// `is_sbus_packet_end` rejects.
+    BadFooter(u8),
+    /// The caller handed over more bytes than fit in a single SBUS frame.
+    ChunkTooLong,
+}
+
+/// A single S.BUS2 telemetry slot, as transmitted back by the receiver after
+/// a frame whose footer indicates telemetry follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Telemetry {
+    pub slot: u8,
+    pub value: [u8; 2],
+}
+
+const SBUS2_TELEMETRY_SLOT_SIZE: usize = 3;
+const SBUS2_TELEMETRY_SLOT_COUNT: u8 = 8;
+
 pub struct Receiver {
-    packet: [u8; 1 + SBUS_PACKET_SIZE],
+    packet: [u8; SBUS_PACKET_SIZE],
     size: usize,
+    telemetry: [u8; SBUS2_TELEMETRY_SLOT_SIZE],
+    telemetry_len: usize,
+    telemetry_slots_remaining: u8,
+}
+
+impl Default for Receiver {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Receiver {
     pub fn new() -> Self {
-        Self { packet: [0u8; 1 + SBUS_PACKET_SIZE], size: 0 }
+        Self {
+            packet: [0u8; SBUS_PACKET_SIZE],
+            size: 0,
+            telemetry: [0u8; SBUS2_TELEMETRY_SLOT_SIZE],
+            telemetry_len: 0,
+            telemetry_slots_remaining: 0,
+        }
     }
 
-    fn continue_receive(&mut self, bytes: &[u8]) -> Option<Data> {
-        let offset = SBUS_PACKET_SIZE - self.size;
-        if is_sbus_packet_end(bytes[offset - 1]) {
-            self.packet[1 + self.size..].copy_from_slice(&bytes[..offset]);
-            self.size = 0;
-            let packet: &Packet = unsafe { core::mem::transmute(&self.packet) };
-            return Some(packet.parse());
+    fn finish_frame(&mut self) -> Option<Data> {
+        let data = Packet::ref_from_bytes(&self.packet).ok().map(Packet::parse)?;
+        self.telemetry_len = 0;
+        self.telemetry_slots_remaining =
+            if data.footer_kind.is_sbus2() { SBUS2_TELEMETRY_SLOT_COUNT } else { 0 };
+        Some(data)
+    }
+
+    /// Reassemble the 8 fixed 3-byte telemetry slots that trail an S.BUS2 frame,
+    /// using the same partial-buffer approach as `receive`. Returns one decoded
+    /// slot per completed 3-byte group; bytes beyond the first completed slot in
+    /// a single call are dropped, matching `receive`'s one-frame-per-call model.
+    pub fn receive_telemetry(&mut self, bytes: &[u8]) -> Option<Telemetry> {
+        if self.telemetry_slots_remaining == 0 {
+            return None;
+        }
+        let needed = SBUS2_TELEMETRY_SLOT_SIZE - self.telemetry_len;
+        let take = needed.min(bytes.len());
+        self.telemetry[self.telemetry_len..self.telemetry_len + take]
+            .copy_from_slice(&bytes[..take]);
+        self.telemetry_len += take;
+        if self.telemetry_len < SBUS2_TELEMETRY_SLOT_SIZE {
+            return None;
+        }
+        self.telemetry_len = 0;
+        self.telemetry_slots_remaining -= 1;
+        Some(Telemetry { slot: self.telemetry[0], value: [self.telemetry[1], self.telemetry[2]] })
+    }
+
+    fn find_partial_packet(&mut self, bytes: &[u8]) {
+        for i in 0..bytes.len() {
+            if bytes[i] == SBUS_PACKET_BEGIN {
+                self.size = bytes.len() - i;
+                self.packet[..self.size].copy_from_slice(&bytes[i..]);
+                break;
+            }
         }
-        for i in 1..self.size {
-            let size = self.size - i;
+    }
+
+    fn continue_receive(&mut self, bytes: &[u8]) -> Result<Option<Data>, ReceiveError> {
+        let mut bad_footer = None;
+        for offset in 0..self.size {
+            if self.packet[offset] != SBUS_PACKET_BEGIN {
+                continue;
+            }
+            let size = self.size - offset;
             let remain_size = SBUS_PACKET_SIZE - size;
-            let last = bytes[remain_size - 1];
-            if self.packet[1 + i] == SBUS_PACKET_BEGIN && is_sbus_packet_end(last) {
-                self.packet.copy_within(1 + i..1 + self.size, 1);
-                self.packet[1 + size..].copy_from_slice(&bytes[..remain_size]);
-                self.size = 0;
-                let packet: &Packet = unsafe { core::mem::transmute(&self.packet) };
-                return Some(packet.parse());
+            if bytes.len() < remain_size {
+                self.packet.copy_within(offset..self.size, 0);
+                self.packet[size..size + bytes.len()].copy_from_slice(bytes);
+                self.size = size + bytes.len();
+                return Ok(None);
+            }
+            let footer = bytes[remain_size - 1];
+            if !is_sbus_packet_end(footer) {
+                bad_footer = Some(footer);
+                continue;
             }
+            self.packet.copy_within(offset..self.size, 0);
+            self.packet[size..].copy_from_slice(&bytes[..remain_size]);
+            self.size = 0;
+            return Ok(self.finish_frame());
         }
         self.size = 0;
-        None
+        self.find_partial_packet(bytes);
+        // offset 0 always holds SBUS_PACKET_BEGIN whenever self.size > 0 (the
+        // only way find_partial_packet sets self.size > 0), so that iteration
+        // always either returns above or records a bad footer here.
+        Err(ReceiveError::BadFooter(bad_footer.expect("offset 0 is always a candidate start")))
     }
 
-    // Assuming only one or none SBUS packet exists
-    pub fn receive(&mut self, bytes: &[u8]) -> Option<Data> {
-        assert!(bytes.len() >= SBUS_PACKET_SIZE);
+    /// Must be chunk of SBUS PACKET SIZE or less
+    pub fn receive(&mut self, bytes: &[u8]) -> Result<Option<Data>, ReceiveError> {
+        if bytes.len() > SBUS_PACKET_SIZE {
+            return Err(ReceiveError::ChunkTooLong);
+        }
         if self.size > 0 {
-            if let Some(data) = self.continue_receive(bytes) {
+            return self.continue_receive(bytes);
+        }
+        if bytes.len() == SBUS_PACKET_SIZE && bytes[0] == SBUS_PACKET_BEGIN {
+            let footer = bytes[SBUS_PACKET_SIZE - 1];
+            if is_sbus_packet_end(footer) {
+                self.packet.copy_from_slice(bytes);
+                return Ok(self.finish_frame());
+            }
+            self.find_partial_packet(&bytes[1..]);
+            return Err(ReceiveError::BadFooter(footer));
+        }
+        self.find_partial_packet(bytes);
+        Ok(None)
+    }
+
+    /// Feed an arbitrary-length, non-frame-aligned slice (e.g. a UART DMA
+    /// buffer) and iterate over every complete frame discovered in it,
+    /// resynchronizing on `SBUS_PACKET_BEGIN` plus a valid footer as it goes.
+    /// Bad-footer and chunk-too-long conditions are resync attempts, not
+    /// failures, so they're silently skipped here; use `receive` directly if
+    /// you need to observe them.
+    pub fn push<'a, 'b>(&'a mut self, bytes: &'b [u8]) -> Push<'a, 'b> {
+        Push { receiver: self, bytes }
+    }
+
+    /// Feed an arbitrary-length telemetry chunk and iterate over every
+    /// complete slot discovered in it, mirroring how `push` pairs with
+    /// `receive` for channel frames.
+    pub fn push_telemetry<'a, 'b>(&'a mut self, bytes: &'b [u8]) -> PushTelemetry<'a, 'b> {
+        PushTelemetry { receiver: self, bytes }
+    }
+}
+
+pub struct Push<'a, 'b> {
+    receiver: &'a mut Receiver,
+    bytes: &'b [u8],
+}
+
+impl Iterator for Push<'_, '_> {
+    type Item = Data;
+
+    fn next(&mut self) -> Option<Data> {
+        while !self.bytes.is_empty() {
+            // While a partial frame is buffered, hand over only the bytes that
+            // complete it: `receive` drops whatever follows a completed frame
+            // in the same call.
+            let needed = if self.receiver.size > 0 {
+                SBUS_PACKET_SIZE - self.receiver.size
+            } else {
+                SBUS_PACKET_SIZE
+            };
+            let take = self.bytes.len().min(needed);
+            let (chunk, rest) = self.bytes.split_at(take);
+            self.bytes = rest;
+            if let Ok(Some(data)) = self.receiver.receive(chunk) {
                 return Some(data);
             }
         }
-        for i in 0..bytes.len() {
-            if bytes[i] == SBUS_PACKET_BEGIN {
-                if i + SBUS_PACKET_SIZE <= bytes.len() {
-                    self.packet[1..].copy_from_slice(&bytes[i..i + SBUS_PACKET_SIZE]);
-                    let packet: &Packet = unsafe { core::mem::transmute(&self.packet) };
-                    return Some(packet.parse());
-                } else {
-                    self.size = bytes.len() - i;
-                    self.packet[1..1 + self.size].copy_from_slice(&bytes[i..]);
-                    break;
-                }
+        None
+    }
+}
+
+pub struct PushTelemetry<'a, 'b> {
+    receiver: &'a mut Receiver,
+    bytes: &'b [u8],
+}
+
+impl Iterator for PushTelemetry<'_, '_> {
+    type Item = Telemetry;
+
+    fn next(&mut self) -> Option<Telemetry> {
+        while !self.bytes.is_empty() {
+            // `receive_telemetry` drops anything past what completes its
+            // current slot in a single call, so feed exactly that much at a
+            // time instead of a fixed-size chunk to avoid losing bytes.
+            let needed = SBUS2_TELEMETRY_SLOT_SIZE - self.receiver.telemetry_len;
+            let take = needed.min(self.bytes.len());
+            let (chunk, rest) = self.bytes.split_at(take);
+            self.bytes = rest;
+            if let Some(telemetry) = self.receiver.receive_telemetry(chunk) {
+                return Some(telemetry);
             }
         }
         None
@@ -119,17 +396,19 @@ impl Receiver {
 
 #[cfg(test)]
 mod tests {
+    use zerocopy::FromBytes;
+
     #[test]
     fn test_sbus() {
         use super::{Data, Packet, SBUS_PACKET_SIZE};
 
         assert_eq!(SBUS_PACKET_SIZE, 25);
-        let bytes: [u8; SBUS_PACKET_SIZE + 1] = hex!(
-            "00 0F E0 03 1F 58 C0 07 16 B0 80 05 2C 60 01 0B
+        let bytes: [u8; SBUS_PACKET_SIZE] = hex!(
+            "0F E0 03 1F 58 C0 07 16 B0 80 05 2C 60 01 0B
              F8 C0 07 00 00 00 00 00 03 00"
         );
 
-        let sbus_packet: &Packet = unsafe { core::mem::transmute(&bytes) };
+        let sbus_packet = Packet::ref_from_bytes(&bytes).unwrap();
         assert_eq!(
             sbus_packet.parse(),
             Data {
@@ -138,6 +417,7 @@ mod tests {
                 channel18: false,
                 frame_lost: false,
                 failsafe: false,
+                footer_kind: super::FooterKind::Sbus1,
             }
         )
     }
@@ -149,7 +429,7 @@ mod tests {
         let mut receiver = Receiver::new();
         let bytes: [u8; SBUS_PACKET_SIZE] =
             hex!("0F E0 03 1F 58 C0 07 16 B0 80 05 2C 60 01 0B F8 C0 07 00 00 00 00 00 03 00");
-        assert!(receiver.receive(&bytes).is_some());
+        assert!(receiver.receive(&bytes).unwrap().is_some());
     }
 
     #[test]
@@ -159,7 +439,7 @@ mod tests {
         let mut receiver = Receiver::new();
         let bytes: [u8; SBUS_PACKET_SIZE] =
             hex!("FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF");
-        assert!(receiver.receive(&bytes).is_none());
+        assert_eq!(receiver.receive(&bytes), Ok(None));
         assert_eq!(receiver.size, 0);
     }
 
@@ -171,15 +451,201 @@ mod tests {
 
         let bytes: [u8; SBUS_PACKET_SIZE] =
             hex!("00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 0F 00");
-        assert!(receiver.receive(&bytes).is_none());
+        assert_eq!(receiver.receive(&bytes), Ok(None));
         assert_eq!(receiver.size, 2);
 
         let bytes: [u8; SBUS_PACKET_SIZE] =
             hex!("FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF 00 FF FF");
-        assert!(receiver.receive(&bytes).is_some());
+        assert!(receiver.receive(&bytes).unwrap().is_some());
         assert_eq!(receiver.size, 0);
     }
 
+    #[test]
+    fn test_chunk_too_long() {
+        use super::{Receiver, ReceiveError, SBUS_PACKET_SIZE};
+
+        let mut receiver = Receiver::new();
+        let bytes = [0u8; SBUS_PACKET_SIZE + 1];
+        assert_eq!(receiver.receive(&bytes), Err(ReceiveError::ChunkTooLong));
+    }
+
+    #[test]
+    fn test_bad_footer() {
+        use super::{Receiver, ReceiveError, SBUS_PACKET_SIZE};
+
+        let mut receiver = Receiver::new();
+        let bytes: [u8; SBUS_PACKET_SIZE] =
+            hex!("0F E0 03 1F 58 C0 07 16 B0 80 05 2C 60 01 0B F8 C0 07 00 00 00 00 00 03 FF");
+        assert_eq!(receiver.receive(&bytes), Err(ReceiveError::BadFooter(0xFF)));
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        use super::{Data, Packet, SBUS_PACKET_SIZE};
+
+        fn decode_frame(frame: &[u8; SBUS_PACKET_SIZE]) -> Data {
+            Packet::ref_from_bytes(frame).unwrap().parse()
+        }
+
+        use super::FooterKind;
+
+        let cases = [
+            Data { channels: [992; 16], ..Data::default() },
+            Data { channels: [0; 16], ..Data::default() },
+            Data { channels: [2047; 16], ..Data::default() },
+            Data {
+                channels: [
+                    992, 352, 1700, 1, 1024, 511, 2046, 0, 992, 352, 1700, 1, 1024, 511, 2046, 0,
+                ],
+                channel17: true,
+                channel18: false,
+                frame_lost: true,
+                failsafe: false,
+                ..Data::default()
+            },
+            Data {
+                channels: [0; 16],
+                channel17: false,
+                channel18: true,
+                frame_lost: false,
+                failsafe: true,
+                ..Data::default()
+            },
+            Data { footer_kind: FooterKind::Sbus2ReceiverVoltage, ..Data::default() },
+            Data { footer_kind: FooterKind::Sbus2GpsOrBaro, ..Data::default() },
+            Data { footer_kind: FooterKind::Sbus2Unknown(0x24), ..Data::default() },
+        ];
+
+        for data in cases {
+            let frame = data.encode();
+            assert_eq!(frame[0], super::SBUS_PACKET_BEGIN);
+            assert_eq!(decode_frame(&frame), data);
+        }
+    }
+
+    #[test]
+    fn test_push_multiple_frames_in_one_buffer() {
+        use super::{Receiver, SBUS_PACKET_SIZE};
+
+        let frame: [u8; SBUS_PACKET_SIZE] =
+            hex!("0F E0 03 1F 58 C0 07 16 B0 80 05 2C 60 01 0B F8 C0 07 00 00 00 00 00 03 00");
+
+        let mut bytes = [0u8; 2 * SBUS_PACKET_SIZE];
+        bytes[..SBUS_PACKET_SIZE].copy_from_slice(&frame);
+        bytes[SBUS_PACKET_SIZE..].copy_from_slice(&frame);
+
+        let mut receiver = Receiver::new();
+        let mut iter = receiver.push(&bytes);
+        assert_eq!(iter.next().unwrap().channels[0], 992);
+        assert_eq!(iter.next().unwrap().channels[0], 992);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_push_frame_misaligned_with_chunk_boundary() {
+        use super::{Receiver, SBUS_PACKET_SIZE};
+
+        let frame: [u8; SBUS_PACKET_SIZE] =
+            hex!("0F E0 03 1F 58 C0 07 16 B0 80 05 2C 60 01 0B F8 C0 07 00 00 00 00 00 03 00");
+
+        let mut bytes = [0xFFu8; 5 + SBUS_PACKET_SIZE];
+        bytes[5..].copy_from_slice(&frame);
+
+        let mut receiver = Receiver::new();
+        let data = receiver.push(&bytes).next().unwrap();
+        assert_eq!(data.channels[0], 992);
+    }
+
+    #[test]
+    fn test_channel_us_roundtrip() {
+        use super::Data;
+
+        let mut data = Data::default();
+        data.channels[0] = Data::from_us(1000);
+        assert_eq!(data.channels[0], 172);
+        assert_eq!(data.channel_us(0), 1000);
+
+        data.channels[0] = Data::from_us(1500);
+        assert_eq!(data.channels[0], 992);
+        assert_eq!(data.channel_us(0), 1500);
+
+        data.channels[0] = Data::from_us(2000);
+        assert_eq!(data.channels[0], 1811);
+        assert_eq!(data.channel_us(0), 2000);
+
+        // out-of-range inputs clamp to the standard S.BUS range
+        assert_eq!(Data::from_us(0), 172);
+        assert_eq!(Data::from_us(u16::MAX), 1811);
+    }
+
+    #[test]
+    #[cfg(feature = "float-channels")]
+    fn test_channel_norm_roundtrip() {
+        use super::Data;
+
+        let mut data = Data::default();
+        data.channels[0] = Data::from_norm(-1.0);
+        assert_eq!(data.channels[0], 173);
+
+        data.channels[0] = Data::from_norm(0.0);
+        assert_eq!(data.channels[0], 992);
+        assert_eq!(data.channel_norm(0), 0.0);
+
+        data.channels[0] = Data::from_norm(1.0);
+        assert_eq!(data.channels[0], 1811);
+
+        // out-of-range inputs clamp to -1.0..1.0
+        assert_eq!(Data::from_norm(-2.0), 173);
+        assert_eq!(Data::from_norm(2.0), 1811);
+    }
+
+    #[test]
+    fn test_sbus2_telemetry() {
+        use super::{FooterKind, Receiver, Telemetry, SBUS_PACKET_SIZE};
+
+        let mut receiver = Receiver::new();
+        let bytes: [u8; SBUS_PACKET_SIZE] =
+            hex!("0F E0 03 1F 58 C0 07 16 B0 80 05 2C 60 01 0B F8 C0 07 00 00 00 00 00 03 04");
+        let data = receiver.receive(&bytes).unwrap().unwrap();
+        assert_eq!(data.footer_kind, FooterKind::Sbus2ReceiverVoltage);
+
+        for slot in 0..8u8 {
+            let value = [slot, slot.wrapping_mul(2)];
+            let telemetry = receiver.receive_telemetry(&[slot, value[0], value[1]]);
+            assert_eq!(telemetry, Some(Telemetry { slot, value }));
+        }
+        assert_eq!(receiver.receive_telemetry(&[0, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_push_telemetry_arbitrary_chunking() {
+        use super::{FooterKind, Receiver, Telemetry, SBUS_PACKET_SIZE};
+
+        let mut receiver = Receiver::new();
+        let bytes: [u8; SBUS_PACKET_SIZE] =
+            hex!("0F E0 03 1F 58 C0 07 16 B0 80 05 2C 60 01 0B F8 C0 07 00 00 00 00 00 03 04");
+        let data = receiver.receive(&bytes).unwrap().unwrap();
+        assert_eq!(data.footer_kind, FooterKind::Sbus2ReceiverVoltage);
+
+        let mut slots = [0u8; 8 * 3];
+        for slot in 0..8u8 {
+            slots[slot as usize * 3] = slot;
+            slots[slot as usize * 3 + 1] = slot.wrapping_mul(2);
+            slots[slot as usize * 3 + 2] = slot.wrapping_mul(3);
+        }
+
+        // Feed the slots in chunks that don't line up with the 3-byte slot
+        // boundaries to exercise the same partial-buffer reassembly `push`
+        // exercises for frames.
+        let mut found: Vec<Telemetry> = receiver.push_telemetry(&slots[..5]).collect();
+        found.extend(receiver.push_telemetry(&slots[5..]));
+
+        let expected: Vec<Telemetry> = (0..8u8)
+            .map(|slot| Telemetry { slot, value: [slot.wrapping_mul(2), slot.wrapping_mul(3)] })
+            .collect();
+        assert_eq!(found, expected);
+    }
+
     #[test]
     fn test_header_not_sbus() {
         use super::{Receiver, SBUS_PACKET_SIZE};
@@ -188,14 +654,14 @@ mod tests {
 
         let bytes: [u8; SBUS_PACKET_SIZE] =
             hex!("00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 0F 0F 01");
-        assert!(receiver.receive(&bytes).is_none());
+        assert_eq!(receiver.receive(&bytes), Ok(None));
         assert_eq!(receiver.size, 3);
 
         let bytes: [u8; SBUS_PACKET_SIZE] =
             hex!("FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF FF 02 00 FF FF");
-        assert!(receiver.receive(&bytes).is_some());
+        assert!(receiver.receive(&bytes).unwrap().is_some());
         assert_eq!(receiver.size, 0);
-        assert_eq!(receiver.packet[1..3], [0xF, 0x1]);
-        assert_eq!(receiver.packet[1 + SBUS_PACKET_SIZE - 3..], [0xFF, 0x2, 0x0]);
+        assert_eq!(receiver.packet[0..2], [0xF, 0x1]);
+        assert_eq!(receiver.packet[SBUS_PACKET_SIZE - 3..], [0xFF, 0x2, 0x0]);
     }
 }